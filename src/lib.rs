@@ -6,14 +6,20 @@ pub mod types;
 pub mod bond_registry_proxy;
 pub mod uptime_proxy;
 
-use types::{Proposal, ProposalStatus, VoteDirection, VoteRecord};
+use types::{
+    Action, CurveFunction, ExecutionCursor, GuardianSet, LinearCurve, OngoingOperation,
+    OperationCompletionStatus, OperationKind, Proposal, ProposalFullInfo, ProposalStatus,
+    StreamInfo, VoteDirection, VoteRecord,
+};
 
 // ============================================================
 // Constants
 // ============================================================
 
-/// 51% quorum — yes votes must be >= 51% of total shares AND yes > no
-const QUORUM_PERCENTAGE: u64 = 51;
+/// 51% quorum — participating weight (yes+no) must be >= 51/100 of
+/// voting-eligible shares, and yes > no
+const QUORUM_NUMERATOR: u64 = 51;
+const QUORUM_DENOMINATOR: u64 = 100;
 
 /// Maximum single proposal can request: 15% of AUM (1500 basis points)
 const MAX_PROPOSAL_BPS: u64 = 1_500;
@@ -33,6 +39,34 @@ const TIMELOCK_PERIOD: u64 = 86_400;
 /// Dead shares minted on first deposit to prevent inflation attack
 const DEAD_SHARES: u64 = 1_000;
 
+/// Minimum gas that must remain before starting another iteration of a
+/// resumable ongoing operation; below this we persist the cursor and
+/// return `InterruptedBeforeOutOfGas` rather than risk an out-of-gas abort.
+const GAS_SAFETY_THRESHOLD: u64 = 7_000_000;
+
+/// Step budget for a single `continueOperation` call: at most this many
+/// members are processed before the cursor is persisted and control
+/// returns to the caller.
+const OPERATION_STEP_BUDGET: u64 = 200;
+
+/// Floor of the reputation-weighted voting multiplier: 0.5x, in basis points.
+const UPTIME_FACTOR_FLOOR_BPS: u64 = 5_000;
+
+/// Ceiling of the reputation-weighted voting multiplier: 2x, in basis points.
+const UPTIME_FACTOR_CEILING_BPS: u64 = 20_000;
+
+/// Multiplier penalty subtracted per recorded strike, in basis points.
+const STRIKE_PENALTY_BPS: u64 = 500;
+
+/// Trailing window folded into the uptime-credit ratio, in epochs. Bounds
+/// the cross-contract call cost regardless of how long an agent has been
+/// registered.
+const UPTIME_CREDIT_WINDOW_EPOCHS: u64 = 30;
+
+/// Fixed-point scale for the `donate` reward accumulator, matching the
+/// usual MasterChef-style `accRewardPerShare` precision.
+const REWARD_SCALE: u64 = 1_000_000_000_000_000_000;
+
 // ============================================================
 // Contract
 // ============================================================
@@ -57,21 +91,66 @@ pub trait AutonomousFund {
         self.min_uptime_score().set(min_uptime_score);
         self.total_shares().set(BigUint::zero());
         self.proposal_count().set(0u64);
+
+        // Self-amending governance defaults; changeable only via a passed
+        // `ChangeConfig` proposal, never by the owner.
+        self.quorum_numerator().set(QUORUM_NUMERATOR);
+        self.quorum_denominator().set(QUORUM_DENOMINATOR);
+        self.voting_period().set(VOTING_PERIOD);
+        self.timelock_period().set(TIMELOCK_PERIOD);
+        self.proposal_threshold_shares().set(BigUint::zero());
     }
 
     #[upgrade]
     fn upgrade(&self) {}
 
+    // ========================================================
+    // Pause module — emergency circuit breaker
+    //
+    // Unlike governance parameters (only changeable via a passed
+    // proposal), `paused` is an owner-gated emergency brake: it exists
+    // so a misbehaving `bond_registry_address`/`uptime_address`
+    // integration can be stopped immediately, without waiting out a
+    // vote and time-lock. It only ever halts entry points — it cannot
+    // move funds or change economic parameters — so it doesn't weaken
+    // the self-amendment guarantee.
+    // ========================================================
+
+    #[only_owner]
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.paused().set(true);
+        self.paused_event();
+    }
+
+    #[only_owner]
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.paused().set(false);
+        self.unpaused_event();
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+    }
+
     // ========================================================
     // ENDPOINT: deposit
-    // Three-gate membership: identity + reputation + capital
+    // Three-gate membership: identity + reputation + capital.
+    // Accepts EGLD or a single ESDT; shares are minted against that
+    // token's own holdings so multiple assets share one `total_shares`
+    // pool without pretending to know their relative value.
     // ========================================================
 
     #[endpoint(deposit)]
-    #[payable("EGLD")]
+    #[payable("*")]
     fn deposit(&self) {
+        self.require_not_paused();
+
         let caller = self.blockchain().get_caller();
-        let payment_amount = self.call_value().egld_value().clone_value();
+        let payment = self.call_value().egld_or_single_esdt();
+        let token_identifier = payment.token_identifier.clone();
+        let payment_amount = payment.amount.clone();
 
         // ── Gate 3: Capital ──
         require!(
@@ -99,17 +178,25 @@ pub trait AutonomousFund {
             .get_lifetime_info(caller.clone())
             .returns(ReturnsResult)
             .sync_call_readonly();
-        let (_total_heartbeats, lifetime_score, _time_since_last, _time_remaining) =
+        // Same tuple `reputation_weight` destructures below: `(total_periods,
+        // active_periods, successful_cycles, strikes)`. `min_uptime_score`
+        // is a bps threshold against the lifetime active/total ratio.
+        let (total_periods, active_periods, _successful_cycles, _strikes) =
             lifetime_info.into_tuple();
+        let lifetime_uptime_bps = if total_periods == 0 {
+            0u64
+        } else {
+            (active_periods * BPS_DENOMINATOR) / total_periods
+        };
         require!(
-            lifetime_score >= self.min_uptime_score().get(),
+            lifetime_uptime_bps >= self.min_uptime_score().get(),
             "Insufficient uptime reputation"
         );
 
         // ── Share calculation ──
-        let current_aum = self
-            .blockchain()
-            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        // Priced off the `holdings` ledger rather than the SC's live
+        // balance, so a pending `donate()` reward pool (tracked
+        // separately in `reward_pool`) never leaks into NAV.
         let total_shares = self.total_shares().get();
 
         let shares_to_mint = if total_shares == 0u64 {
@@ -119,18 +206,36 @@ pub trait AutonomousFund {
             // Shares = payment amount (1:1 for first deposit)
             // Total shares after this = DEAD_SHARES + payment_amount
             payment_amount.clone()
+        } else if token_identifier.is_egld() && !self.share_curve().is_empty() {
+            // Bonding-curve pricing: invert the cost integral for the
+            // configured curve rather than pricing off pooled NAV. Only
+            // meaningful for EGLD, which is what the curve is denominated in.
+            let curve = self.share_curve().get();
+            self.shares_for_payment(&curve, &total_shares, &payment_amount)
         } else {
-            // shares = payment * total_shares / aum_before_deposit
-            let aum_before = &current_aum - &payment_amount;
-            require!(aum_before > 0u64, "Fund is insolvent");
-            (&payment_amount * &total_shares) / &aum_before
+            // shares = payment * total_shares / this token's holdings before deposit
+            let balance_before = self.holdings(&token_identifier).get();
+            require!(
+                balance_before > 0u64,
+                "Fund holds none of this token yet to price the deposit against"
+            );
+            (&payment_amount * &total_shares) / &balance_before
         };
 
         require!(shares_to_mint > 0u64, "Deposit too small for shares");
 
+        let old_shares = self.shares(&caller).get();
+        self.harvest_all_rewards(&caller, &old_shares);
+
         self.shares(&caller).update(|s| *s += &shares_to_mint);
         self.total_shares().update(|ts| *ts += &shares_to_mint);
         self.members().insert(caller.clone());
+        self.holdings(&token_identifier)
+            .update(|h| *h += &payment_amount);
+        self.held_tokens().insert(token_identifier.clone());
+
+        let new_shares = self.shares(&caller).get();
+        self.resnapshot_all_reward_debt(&caller, &new_shares);
 
         self.deposit_event(&caller, &payment_amount, &shares_to_mint);
     }
@@ -143,6 +248,10 @@ pub trait AutonomousFund {
 
     #[endpoint(withdraw)]
     fn withdraw(&self, share_amount: BigUint) {
+        // Deliberately NOT gated by `require_not_paused` — the circuit
+        // breaker is meant to halt new deposits and proposal execution,
+        // not trap members' capital. Exit must stay available exactly
+        // when pause is most likely to be in effect.
         let caller = self.blockchain().get_caller();
         let user_shares = self.shares(&caller).get();
         require!(
@@ -151,14 +260,25 @@ pub trait AutonomousFund {
         );
 
         let total_shares = self.total_shares().get();
-        let current_aum = self
-            .blockchain()
-            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
 
-        // payout = share_amount * current_aum / total_shares
-        let payout = (&share_amount * &current_aum) / &total_shares;
+        let payout = if !self.share_curve().is_empty() {
+            // Bonding-curve pricing: symmetric integral downward from the
+            // current supply, evaluated over the shares being burned.
+            let curve = self.share_curve().get();
+            curve.sell_refund(&total_shares, &share_amount)
+        } else {
+            // Priced off `holdings`, not the SC's live balance — the
+            // latter also contains the `reward_pool` donations already
+            // earmarked for `claimRewards`, which would otherwise be
+            // paid out twice: once baked into this NAV and once claimed.
+            let current_aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
+            // payout = share_amount * current_aum / total_shares
+            (&share_amount * &current_aum) / &total_shares
+        };
         require!(payout > 0u64, "Nothing to withdraw");
 
+        self.harvest_all_rewards(&caller, &user_shares);
+
         // Update shares
         self.shares(&caller).update(|s| *s -= &share_amount);
         self.total_shares().update(|ts| *ts -= &share_amount);
@@ -168,14 +288,145 @@ pub trait AutonomousFund {
         if remaining == 0u64 {
             self.members().swap_remove(&caller);
         }
+        self.resnapshot_all_reward_debt(&caller, &remaining);
 
         // ── Rage-quit: retroactively remove votes from Passed proposals in time-lock ──
         self.process_rage_quit(&caller);
 
+        self.holdings(&EgldOrEsdtTokenIdentifier::egld())
+            .update(|h| *h -= &payout);
         self.send().direct_egld(&caller, &payout);
         self.withdraw_event(&caller, &payout, &share_amount);
     }
 
+    // ========================================================
+    // ENDPOINT: redeem
+    // Burns shares for a proportional slice of every token the fund
+    // holds, rather than just EGLD — the multi-asset counterpart to
+    // `withdraw`. Each payout is floored; the rounding dust is left
+    // in the fund rather than chased down.
+    // ========================================================
+
+    #[endpoint(redeem)]
+    fn redeem(&self, share_amount: BigUint) {
+        // Same exemption as `withdraw`: the breaker must never trap
+        // member capital, so this stays reachable while paused.
+        let caller = self.blockchain().get_caller();
+        let user_shares = self.shares(&caller).get();
+        require!(
+            share_amount > 0u64 && share_amount <= user_shares,
+            "Invalid share amount"
+        );
+
+        let total_shares = self.total_shares().get();
+        require!(total_shares > 0u64, "No shares outstanding");
+
+        self.harvest_all_rewards(&caller, &user_shares);
+
+        self.shares(&caller).update(|s| *s -= &share_amount);
+        self.total_shares().update(|ts| *ts -= &share_amount);
+
+        let remaining = self.shares(&caller).get();
+        if remaining == 0u64 {
+            self.members().swap_remove(&caller);
+        }
+        self.resnapshot_all_reward_debt(&caller, &remaining);
+
+        // ── Rage-quit: retroactively remove votes from Passed proposals in time-lock ──
+        self.process_rage_quit(&caller);
+
+        let mut redeemed_any = false;
+        for token in self.held_tokens().iter() {
+            let holding = self.holdings(&token).get();
+            if holding == 0u64 {
+                continue;
+            }
+            let payout = (&holding * &share_amount) / &total_shares;
+            if payout == 0u64 {
+                continue;
+            }
+
+            self.holdings(&token).update(|h| *h -= &payout);
+            if token.is_egld() {
+                self.send().direct_egld(&caller, &payout);
+            } else {
+                self.send()
+                    .direct_esdt(&caller, &token.clone().unwrap_esdt(), 0, &payout);
+            }
+            redeemed_any = true;
+        }
+        require!(redeemed_any, "Nothing to redeem");
+
+        self.redeem_event(&caller, &share_amount);
+    }
+
+    // ========================================================
+    // ENDPOINT: donate
+    // The CosmWasm "Donate" pattern: funds are split pro-rata among
+    // existing shareholders instead of minting new shares to the donor.
+    // Unbounded membership rules out paying everyone out in one
+    // transaction, so accrual is tracked via a `reward_per_share`
+    // running total and settled lazily, the same accumulator MasterChef
+    // made standard for this exact problem.
+    // ========================================================
+
+    #[endpoint(donate)]
+    #[payable("*")]
+    fn donate(&self) {
+        self.require_not_paused();
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let token_identifier = payment.token_identifier.clone();
+        let donation_amount = payment.amount.clone();
+        require!(donation_amount > 0u64, "Donation must be non-zero");
+
+        let total_shares = self.total_shares().get();
+        require!(total_shares > 0u64, "No shareholders to donate to");
+
+        let increment = (&donation_amount * REWARD_SCALE) / &total_shares;
+        self.reward_per_share(&token_identifier)
+            .update(|r| *r += &increment);
+        // Tracked in `reward_pool`, not `holdings` — donated funds are
+        // earmarked entirely for `claimRewards` and must never back
+        // `withdraw`/`redeem` NAV pricing, or the same EGLD would be
+        // paid out twice (once via share-price appreciation, once via
+        // the explicit claim).
+        self.reward_pool(&token_identifier)
+            .update(|r| *r += &donation_amount);
+        self.held_tokens().insert(token_identifier.clone());
+
+        self.donate_event(&self.blockchain().get_caller(), &token_identifier, &donation_amount);
+    }
+
+    // ========================================================
+    // ENDPOINT: claimRewards
+    // Pays out a single token's accrued donation share. Settles first
+    // so a donation landing since the holder's last touch is included.
+    // ========================================================
+
+    #[endpoint(claimRewards)]
+    fn claim_rewards(&self, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        let current_shares = self.shares(&caller).get();
+        self.harvest_reward(&caller, &token, &current_shares);
+        self.reward_debt(&caller, &token)
+            .set(self.accrued_reward(&current_shares, &token));
+
+        let claimable = self.claimable_rewards(&caller, &token).get();
+        require!(claimable > 0u64, "Nothing to claim");
+        self.claimable_rewards(&caller, &token).clear();
+
+        self.reward_pool(&token).update(|r| *r -= &claimable);
+        if token.is_egld() {
+            self.send().direct_egld(&caller, &claimable);
+        } else {
+            self.send()
+                .direct_esdt(&caller, &token.clone().unwrap_esdt(), 0, &claimable);
+        }
+
+        self.rewards_claimed_event(&caller, &token, &claimable);
+    }
+
     // ========================================================
     // ENDPOINT: submitProposal
     // Any member can propose. Links to Bulletin Board discussion.
@@ -185,8 +436,7 @@ pub trait AutonomousFund {
     fn submit_proposal(
         &self,
         description: ManagedBuffer,
-        receiver: ManagedAddress,
-        amount: BigUint,
+        action: Action<Self::Api>,
         bulletin_post_id: u64,
     ) -> u64 {
         let caller = self.blockchain().get_caller();
@@ -194,17 +444,53 @@ pub trait AutonomousFund {
             self.members().contains(&caller),
             "Only members can propose"
         );
-
-        // ── Guardrail: per-proposal cap at 15% of AUM ──
-        let current_aum = self
-            .blockchain()
-            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
-        let max_amount = (&current_aum * MAX_PROPOSAL_BPS) / BPS_DENOMINATOR;
         require!(
-            amount <= max_amount,
-            "Exceeds 15% of AUM per-proposal cap"
+            self.shares(&caller).get() >= self.proposal_threshold_shares().get(),
+            "Below minimum proposer weight"
         );
 
+        // ── Guardrail: per-proposal cap at 15% of AUM ──
+        // Only meaningful for actions that commit native EGLD; ESDT
+        // transfers and async calls don't draw down native AUM the same
+        // way, so they skip this particular gate. `DistributeSurplus`
+        // draws it down exactly like `SendEgld`, and `StreamPayout`
+        // commits to `amount_per_epoch * epoch_count` over its
+        // lifetime, so both are checked against the same cap.
+        let egld_committed = match &action {
+            Action::SendEgld { amount, .. } => Some(amount.clone()),
+            Action::DistributeSurplus { amount } => Some(amount.clone()),
+            Action::StreamPayout {
+                amount_per_epoch,
+                start_epoch,
+                end_epoch,
+                ..
+            } => {
+                require!(
+                    end_epoch >= start_epoch,
+                    "Stream end epoch must be >= start epoch"
+                );
+                // Checked, not raw `end_epoch - start_epoch + 1`: a
+                // proposer picking `start_epoch: 0, end_epoch: u64::MAX`
+                // would otherwise overflow the `+ 1` and wrap to a tiny
+                // `epoch_count`, trivially passing this very cap while the
+                // real stream still accrues against the unbounded
+                // `end_epoch` in `claimStream`.
+                let epoch_count = (end_epoch - start_epoch)
+                    .checked_add(1)
+                    .unwrap_or_else(|| sc_panic!("Stream epoch range too large"));
+                Some(amount_per_epoch * epoch_count)
+            }
+            _ => None,
+        };
+        if let Some(amount) = egld_committed {
+            let current_aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
+            let max_amount = (&current_aum * MAX_PROPOSAL_BPS) / BPS_DENOMINATOR;
+            require!(
+                amount <= max_amount,
+                "Exceeds 15% of AUM per-proposal cap"
+            );
+        }
+
         let proposal_id = self.proposal_count().get() + 1u64;
         let timestamp = self.blockchain().get_block_timestamp();
 
@@ -212,14 +498,15 @@ pub trait AutonomousFund {
             id: proposal_id,
             proposer: caller.clone(),
             description,
-            receiver,
-            amount,
+            action,
             status: ProposalStatus::Open,
             yes_votes: BigUint::zero(),
             no_votes: BigUint::zero(),
             created_at: timestamp,
             passed_at: 0u64,
             bulletin_post_id,
+            stream: None,
+            eligible_shares_snapshot: self.voting_shares(),
         };
 
         self.proposals(proposal_id).set(&proposal);
@@ -232,7 +519,7 @@ pub trait AutonomousFund {
 
     // ========================================================
     // ENDPOINT: vote
-    // Yes/No voting weighted by share balance.
+    // Yes/No voting weighted by reputation-adjusted share balance.
     // ========================================================
 
     #[endpoint(vote)]
@@ -260,18 +547,31 @@ pub trait AutonomousFund {
         // Check voting window hasn't expired
         let now = self.blockchain().get_block_timestamp();
         require!(
-            now <= proposal.created_at + VOTING_PERIOD,
+            now <= proposal.created_at + self.voting_period().get(),
             "Voting period has expired"
         );
 
-        let user_shares = self.shares(&caller).get();
-        require!(user_shares > 0u64, "No voting power");
+        let raw_stake = self.shares(&caller).get();
+        require!(raw_stake > 0u64, "No voting power");
+
+        // ── Eligibility gate: must still be a registered agent ──
+        let bond_registry_addr = self.bond_registry_address().get();
+        let agent_name: ManagedBuffer = self
+            .tx()
+            .to(&bond_registry_addr)
+            .typed(bond_registry_proxy::BondRegistryProxy)
+            .get_agent_name(caller.clone())
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        require!(!agent_name.is_empty(), "Not a registered agent");
+
+        let effective_weight = self.reputation_weight(&caller, &raw_stake);
 
         let direction = if support {
-            proposal.yes_votes += &user_shares;
+            proposal.yes_votes += &effective_weight;
             VoteDirection::Yes
         } else {
-            proposal.no_votes += &user_shares;
+            proposal.no_votes += &effective_weight;
             VoteDirection::No
         };
 
@@ -279,14 +579,258 @@ pub trait AutonomousFund {
         let vote_record = VoteRecord {
             voter: caller.clone(),
             direction,
-            weight: user_shares.clone(),
+            weight: effective_weight.clone(),
+            raw_stake,
         };
         self.vote_records(proposal_id).push(&vote_record);
         self.has_voted(proposal_id, &caller).set(true);
         self.agent_votes(&caller).push(&proposal_id);
         self.proposals(proposal_id).set(&proposal);
 
-        self.vote_event(proposal_id, &caller, support, &user_shares);
+        self.vote_event(proposal_id, &caller, support, &effective_weight);
+    }
+
+    // ========================================================
+    // ENDPOINT: changeVote
+    // Lets a member flip or reaffirm their vote while the proposal is
+    // still Open and within the voting window. Rewrites the existing
+    // `VoteRecord` in place rather than pushing a duplicate, so
+    // `vote_records` never holds more than one entry per voter.
+    // ========================================================
+
+    #[endpoint(changeVote)]
+    fn change_vote(&self, proposal_id: u64, support: bool) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.members().contains(&caller),
+            "Only members can vote"
+        );
+        require!(
+            !self.proposals(proposal_id).is_empty(),
+            "Proposal does not exist"
+        );
+        require!(
+            self.has_voted(proposal_id, &caller).get(),
+            "No existing vote to change"
+        );
+
+        let mut proposal = self.proposals(proposal_id).get();
+        require!(
+            proposal.status == ProposalStatus::Open,
+            "Proposal is not open for voting"
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        require!(
+            now <= proposal.created_at + self.voting_period().get(),
+            "Voting period has expired"
+        );
+
+        let vote_count = self.vote_records(proposal_id).len();
+        let mut record_index = 0u64;
+        for i in 1..=vote_count {
+            if self.vote_records(proposal_id).get(i).voter == caller {
+                record_index = i;
+                break;
+            }
+        }
+        require!(record_index > 0, "No existing vote to change");
+        let old_record = self.vote_records(proposal_id).get(record_index);
+
+        match old_record.direction {
+            VoteDirection::Yes => proposal.yes_votes -= &old_record.weight,
+            VoteDirection::No => proposal.no_votes -= &old_record.weight,
+        }
+
+        let raw_stake = self.shares(&caller).get();
+        require!(raw_stake > 0u64, "No voting power");
+        let new_weight = self.reputation_weight(&caller, &raw_stake);
+
+        let new_direction = if support {
+            proposal.yes_votes += &new_weight;
+            VoteDirection::Yes
+        } else {
+            proposal.no_votes += &new_weight;
+            VoteDirection::No
+        };
+
+        let new_record = VoteRecord {
+            voter: caller.clone(),
+            direction: new_direction,
+            weight: new_weight.clone(),
+            raw_stake,
+        };
+        self.vote_records(proposal_id).set(record_index, &new_record);
+        self.proposals(proposal_id).set(&proposal);
+
+        let old_support = old_record.direction == VoteDirection::Yes;
+        self.vote_changed_event(
+            proposal_id,
+            &caller,
+            old_support,
+            support,
+            &old_record.weight,
+            &new_weight,
+        );
+    }
+
+    // ========================================================
+    // INTERNAL: reputation-weighted vote power
+    // effective_weight = raw_stake * uptime_factor, where the factor
+    // folds UptimeProxy::get_epoch_credits over the trailing
+    // UPTIME_CREDIT_WINDOW_EPOCHS into an earned/slots ratio (richer
+    // signal than a lifetime pass/fail gate), penalized by strikes from
+    // get_lifetime_info, then clamped to
+    // [UPTIME_FACTOR_FLOOR_BPS, UPTIME_FACTOR_CEILING_BPS].
+    // ========================================================
+
+    fn reputation_weight(&self, agent: &ManagedAddress, raw_stake: &BigUint) -> BigUint {
+        let uptime_addr = self.uptime_address().get();
+
+        let lifetime_info: MultiValue4<u64, u64, u64, u64> = self
+            .tx()
+            .to(&uptime_addr)
+            .typed(uptime_proxy::UptimeProxy)
+            .get_lifetime_info(agent.clone())
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        let (_total_periods, _active_periods, _successful_cycles, strikes) =
+            lifetime_info.into_tuple();
+
+        let epoch_credits: MultiValueEncoded<MultiValue3<u64, u64, u64>> = self
+            .tx()
+            .to(&uptime_addr)
+            .typed(uptime_proxy::UptimeProxy)
+            .get_epoch_credits(agent.clone(), UPTIME_CREDIT_WINDOW_EPOCHS)
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+
+        let mut total_earned = 0u64;
+        let mut total_slots = 0u64;
+        for entry in epoch_credits {
+            let (_epoch, credits, prev_credits) = entry.into_tuple();
+            total_earned += credits.saturating_sub(prev_credits);
+            total_slots += 1;
+        }
+
+        let ratio_bps = if total_slots == 0 {
+            BPS_DENOMINATOR
+        } else {
+            (total_earned * BPS_DENOMINATOR) / total_slots
+        };
+        let penalty_bps = strikes.saturating_mul(STRIKE_PENALTY_BPS);
+        let uptime_factor_bps = ratio_bps
+            .saturating_sub(penalty_bps)
+            .clamp(UPTIME_FACTOR_FLOOR_BPS, UPTIME_FACTOR_CEILING_BPS);
+
+        (raw_stake * uptime_factor_bps) / BPS_DENOMINATOR
+    }
+
+    // ========================================================
+    // ENDPOINT: submitSignedVotes
+    // Lets a guardian committee ratify a proposal with one on-chain
+    // transaction carrying M-of-N off-chain signatures, instead of every
+    // member sending an individual `vote` tx. Each signature is verified
+    // over `sha256(proposal_id || guardian_nonce)`; once at least
+    // `threshold` distinct valid signatures are on record the proposal
+    // is ratified straight to `Passed`, provided its action only touches
+    // governance parameters (see `guardian_ratifiable` below); other
+    // proposals only have their signatures recorded.
+    // ========================================================
+
+    #[endpoint(submitSignedVotes)]
+    fn submit_signed_votes(
+        &self,
+        proposal_id: u64,
+        guardian_set_index: u64,
+        signatures: MultiValueEncoded<MultiValue2<u32, ManagedByteArray<Self::Api, 64>>>,
+    ) {
+        require!(
+            !self.proposals(proposal_id).is_empty(),
+            "Proposal does not exist"
+        );
+        let mut proposal = self.proposals(proposal_id).get();
+        require!(
+            proposal.status == ProposalStatus::Open,
+            "Proposal is not open for ratification"
+        );
+        require!(
+            guardian_set_index == self.current_guardian_set_index().get(),
+            "Guardian set is not the current one"
+        );
+        require!(
+            !self.guardian_sets(guardian_set_index).is_empty(),
+            "No guardian set configured"
+        );
+        let guardian_set = self.guardian_sets(guardian_set_index).get();
+
+        let mut digest_bytes = ManagedBuffer::new_from_bytes(&proposal_id.to_be_bytes());
+        digest_bytes.append(&ManagedBuffer::new_from_bytes(
+            &self.guardian_nonce().get().to_be_bytes(),
+        ));
+        let digest = self.crypto().sha256(&digest_bytes);
+
+        // Bits recorded against a different digest (an earlier guardian
+        // set) don't carry over to the current set.
+        let mut bitmap = if !self.guardian_vote_digest(proposal_id).is_empty()
+            && self.guardian_vote_digest(proposal_id).get() == digest
+        {
+            self.guardian_signature_bitmap(proposal_id).get()
+        } else {
+            0u64
+        };
+        self.guardian_vote_digest(proposal_id).set(&digest);
+        for pair in signatures {
+            let (signer_index, signature) = pair.into_tuple();
+            require!(
+                (signer_index as usize) < guardian_set.guardians.len(),
+                "Signer index out of range"
+            );
+            let bit = 1u64 << signer_index;
+            if bitmap & bit != 0 {
+                // Already counted — skip rather than fail the whole batch.
+                continue;
+            }
+
+            let guardian = guardian_set.guardians.get(signer_index as usize);
+            let valid = self.crypto().verify_ed25519(
+                guardian.as_managed_buffer(),
+                digest.as_managed_buffer(),
+                signature.as_managed_buffer(),
+            );
+            if valid {
+                bitmap |= bit;
+            }
+        }
+        self.guardian_signature_bitmap(proposal_id).set(bitmap);
+
+        let valid_count = bitmap.count_ones();
+        self.guardian_votes_submitted_event(proposal_id, valid_count);
+
+        // Guardian ratification is a fast path around the normal
+        // vote/quorum tally, so it must not be usable to bypass the
+        // quorum+threshold safeguards the rest of the series builds on
+        // fund-moving actions. It's restricted to proposals that only
+        // touch governance parameters, never to ones that send or
+        // stream out EGLD/ESDT.
+        let guardian_ratifiable = matches!(
+            proposal.action,
+            Action::ChangeConfig { .. }
+                | Action::SetShareCurve { .. }
+                | Action::CancelStream { .. }
+                | Action::SetGuardianSet { .. }
+        );
+
+        if valid_count >= guardian_set.threshold
+            && proposal.status == ProposalStatus::Open
+            && guardian_ratifiable
+        {
+            let now = self.blockchain().get_block_timestamp();
+            proposal.status = ProposalStatus::Passed;
+            proposal.passed_at = now;
+            self.proposals(proposal_id).set(&proposal);
+            self.proposal_passed_event(proposal_id, now);
+        }
     }
 
     // ========================================================
@@ -309,14 +853,11 @@ pub trait AutonomousFund {
 
         let now = self.blockchain().get_block_timestamp();
         require!(
-            now > proposal.created_at + VOTING_PERIOD,
+            now > proposal.created_at + self.voting_period().get(),
             "Voting period has not ended"
         );
 
-        let effective_shares = self.voting_shares();
-        let quorum_requirement = (&effective_shares * QUORUM_PERCENTAGE) / 100u64;
-
-        if proposal.yes_votes >= quorum_requirement && proposal.yes_votes > proposal.no_votes {
+        if self.quorum_met(&proposal) && proposal.yes_votes > proposal.no_votes {
             proposal.status = ProposalStatus::Passed;
             proposal.passed_at = now;
             self.proposals(proposal_id).set(&proposal);
@@ -331,10 +872,18 @@ pub trait AutonomousFund {
     // ========================================================
     // ENDPOINT: executeProposal
     // Only after time-lock. Enforces epoch spending limit.
+    //
+    // Gas-bounded and resumable: if the payout loop runs low on gas
+    // before finishing, progress is persisted in `execution_cursor`
+    // and this endpoint returns `InterruptedBeforeOutOfGas`. The
+    // caller re-invokes the same endpoint to continue; the proposal
+    // only moves to `Executed` once the loop reports `Completed`.
     // ========================================================
 
     #[endpoint(executeProposal)]
-    fn execute_proposal(&self, proposal_id: u64) {
+    fn execute_proposal(&self, proposal_id: u64) -> OperationCompletionStatus {
+        self.require_not_paused();
+
         let caller = self.blockchain().get_caller();
         require!(
             self.members().contains(&caller),
@@ -351,14 +900,12 @@ pub trait AutonomousFund {
         if proposal.status == ProposalStatus::Passed {
             let now = self.blockchain().get_block_timestamp();
             require!(
-                now > proposal.passed_at + TIMELOCK_PERIOD,
+                now > proposal.passed_at + self.timelock_period().get(),
                 "Time-lock period has not elapsed"
             );
 
             // Re-verify quorum still holds after potential rage-quits
-            let effective_shares = self.voting_shares();
-            let quorum_requirement = (&effective_shares * QUORUM_PERCENTAGE) / 100u64;
-            if proposal.yes_votes < quorum_requirement || proposal.yes_votes <= proposal.no_votes {
+            if !self.quorum_met(&proposal) || proposal.yes_votes <= proposal.no_votes {
                 proposal.status = ProposalStatus::Failed;
                 self.proposals(proposal_id).set(&proposal);
                 self.proposal_failed_event(proposal_id);
@@ -366,6 +913,7 @@ pub trait AutonomousFund {
             }
 
             proposal.status = ProposalStatus::Executable;
+            self.proposals(proposal_id).set(&proposal);
         }
 
         require!(
@@ -373,32 +921,332 @@ pub trait AutonomousFund {
             "Proposal is not executable"
         );
 
-        // ── Guardrail: epoch spending limit ──
+        // ── Guardrail: epoch spending limit, only applicable to EGLD payouts ──
         let current_epoch = self.blockchain().get_block_epoch();
-        let current_aum = self
-            .blockchain()
-            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
-        let epoch_limit = (&current_aum * MAX_EPOCH_SPEND_BPS) / BPS_DENOMINATOR;
-        let already_spent = self.epoch_spent(current_epoch).get();
+        if let Action::SendEgld { amount, .. } = &proposal.action {
+            let current_aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
+            let epoch_limit = (&current_aum * MAX_EPOCH_SPEND_BPS) / BPS_DENOMINATOR;
+            let already_spent = self.epoch_spent(current_epoch).get();
+            require!(
+                &already_spent + amount <= epoch_limit,
+                "Epoch spending limit reached (25% of AUM)"
+            );
+            require!(current_aum >= *amount, "Insufficient fund balance");
+        }
+
+        // Today a proposal carries a single action, so this is a one-item
+        // batch; the cursor is still threaded through so a future
+        // multi-action proposal can reuse the same resumability contract.
+        let mut new_stream: Option<StreamInfo<Self::Api>> = None;
+
+        let mut cursor = if !self.execution_cursor(proposal_id).is_empty() {
+            self.execution_cursor(proposal_id).get()
+        } else {
+            ExecutionCursor {
+                next_index: 0,
+                amount_sent: BigUint::zero(),
+            }
+        };
+
+        if cursor.next_index == 0 {
+            if self.blockchain().get_gas_left() < GAS_SAFETY_THRESHOLD {
+                self.execution_cursor(proposal_id).set(&cursor);
+                self.operation_interrupted_event(proposal_id, cursor.next_index);
+                return OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            }
+
+            match &proposal.action {
+                Action::SendEgld { to, amount } => {
+                    self.holdings(&EgldOrEsdtTokenIdentifier::egld())
+                        .update(|h| *h -= amount);
+                    self.send().direct_egld(to, amount);
+                    cursor.amount_sent = amount.clone();
+                }
+                Action::SendEsdt {
+                    to,
+                    token,
+                    nonce,
+                    amount,
+                } => {
+                    self.holdings(&EgldOrEsdtTokenIdentifier::esdt(token.clone()))
+                        .update(|h| *h -= amount);
+                    self.send().direct_esdt(to, token, *nonce, amount);
+                    cursor.amount_sent = amount.clone();
+                }
+                Action::AsyncCall {
+                    to,
+                    endpoint,
+                    gas,
+                    args,
+                } => {
+                    let mut call = self.tx().to(to).gas(*gas).raw_call(endpoint.clone());
+                    for arg in args {
+                        call = call.argument(&arg);
+                    }
+                    call.transfer_execute();
+                }
+                Action::ChangeConfig {
+                    quorum_numerator,
+                    quorum_denominator,
+                    voting_period,
+                    timelock_period,
+                    proposal_threshold_shares,
+                } => {
+                    require!(*quorum_denominator > 0, "Quorum denominator must be non-zero");
+                    require!(
+                        *quorum_numerator > 0 && quorum_numerator <= quorum_denominator,
+                        "Quorum must be in (0, denominator]"
+                    );
+                    require!(*voting_period > 0, "Voting period must be non-zero");
+                    require!(*timelock_period > 0, "Time-lock period must be non-zero");
+
+                    self.quorum_numerator().set(quorum_numerator);
+                    self.quorum_denominator().set(quorum_denominator);
+                    self.voting_period().set(voting_period);
+                    self.timelock_period().set(timelock_period);
+                    self.proposal_threshold_shares()
+                        .set(proposal_threshold_shares);
+                }
+                Action::SetShareCurve {
+                    initial_price,
+                    slope,
+                } => {
+                    require!(
+                        slope <= &(initial_price * 2u64),
+                        "Slope too steep relative to initial price"
+                    );
+                    self.share_curve().set(&LinearCurve {
+                        initial_price: initial_price.clone(),
+                        slope: slope.clone(),
+                    });
+                }
+                Action::DistributeSurplus { amount } => {
+                    require!(
+                        self.ongoing_operation().is_empty(),
+                        "A fund-wide operation is already in progress"
+                    );
+                    let current_aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
+                    require!(current_aum >= *amount, "Insufficient fund balance");
+
+                    require!(
+                        self.operation_members_snapshot().is_empty(),
+                        "Stale operation member snapshot"
+                    );
+                    for member in self.members().iter() {
+                        self.operation_members_snapshot().push(&member);
+                    }
+
+                    self.ongoing_operation().set(&OngoingOperation {
+                        kind: OperationKind::DistributeSurplus,
+                        next_member_index: 0,
+                        amount_distributed: BigUint::zero(),
+                        total_amount: amount.clone(),
+                        total_shares_snapshot: self.total_shares().get(),
+                    });
+                }
+                Action::StreamPayout {
+                    amount_per_epoch,
+                    start_epoch,
+                    end_epoch,
+                    ..
+                } => {
+                    require!(
+                        end_epoch >= start_epoch,
+                        "Stream end epoch must be >= start epoch"
+                    );
+                    new_stream = Some(StreamInfo {
+                        amount_per_epoch: amount_per_epoch.clone(),
+                        start_epoch: *start_epoch,
+                        end_epoch: *end_epoch,
+                        claimed_so_far: BigUint::zero(),
+                    });
+                }
+                Action::CancelStream { target_proposal_id } => {
+                    require!(
+                        !self.proposals(*target_proposal_id).is_empty(),
+                        "Target proposal does not exist"
+                    );
+                    let mut target = self.proposals(*target_proposal_id).get();
+                    require!(
+                        target.stream.is_some(),
+                        "Target proposal has no active stream"
+                    );
+                    let mut stream = target.stream.clone().unwrap();
+                    if current_epoch < stream.end_epoch {
+                        stream.end_epoch = current_epoch;
+                    }
+                    target.stream = Some(stream);
+                    self.proposals(*target_proposal_id).set(&target);
+                }
+                Action::SetGuardianSet {
+                    guardians,
+                    threshold,
+                } => {
+                    require!(!guardians.is_empty(), "Guardian set cannot be empty");
+                    require!(
+                        guardians.len() <= 64,
+                        "Guardian set cannot exceed 64 members"
+                    );
+                    require!(
+                        *threshold > 0 && (*threshold as usize) <= guardians.len(),
+                        "Threshold must be in (0, guardian count]"
+                    );
+
+                    let next_index = self.current_guardian_set_index().get() + 1u64;
+                    self.guardian_sets(next_index).set(&GuardianSet {
+                        guardians: guardians.clone(),
+                        threshold: *threshold,
+                    });
+                    self.current_guardian_set_index().set(next_index);
+                    // Bumping the nonce invalidates every digest signed
+                    // against the previous guardian set.
+                    self.guardian_nonce().update(|n| *n += 1u64);
+                }
+            }
+
+            cursor.next_index = 1;
+        }
+
+        self.execution_cursor(proposal_id).clear();
+        if let Action::SendEgld { .. } = &proposal.action {
+            self.epoch_spent(current_epoch)
+                .update(|spent| *spent += &cursor.amount_sent);
+        }
+        if let Some(stream) = new_stream {
+            proposal.stream = Some(stream);
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        self.proposals(proposal_id).set(&proposal);
+
+        self.proposal_executed_event(proposal_id);
+        OperationCompletionStatus::Completed
+    }
+
+    // ========================================================
+    // ENDPOINT: continueOperation
+    // Drains the single in-flight fund-wide batch operation (e.g. a
+    // passed "distribute surplus" proposal) by up to
+    // `OPERATION_STEP_BUDGET` members per call. Anyone can call this —
+    // it only ever pays members what the operation already owes them.
+    // Callers re-invoke until this returns `Completed`.
+    // ========================================================
+
+    #[endpoint(continueOperation)]
+    fn continue_operation(&self) -> OperationCompletionStatus {
         require!(
-            &already_spent + &proposal.amount <= epoch_limit,
-            "Epoch spending limit reached (25% of AUM)"
+            !self.ongoing_operation().is_empty(),
+            "No fund-wide operation in progress"
         );
+        let mut op = self.ongoing_operation().get();
 
-        // Verify sufficient balance
+        // Walk the fixed snapshot taken when the operation started, not
+        // the live `members()` set — see `operation_members_snapshot`.
+        let total_members = self.operation_members_snapshot().len() as u64;
+        let batch_end = core::cmp::min(op.next_member_index + OPERATION_STEP_BUDGET, total_members);
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        let mut idx = op.next_member_index;
+        while idx < batch_end {
+            if self.blockchain().get_gas_left() < GAS_SAFETY_THRESHOLD {
+                break;
+            }
+            let member = self.operation_members_snapshot().get((idx + 1) as usize);
+
+            match op.kind {
+                OperationKind::DistributeSurplus => {
+                    let member_shares = self.shares(&member).get();
+                    let payout = if op.total_shares_snapshot == 0u64 {
+                        BigUint::zero()
+                    } else {
+                        (&member_shares * &op.total_amount) / &op.total_shares_snapshot
+                    };
+                    if payout > 0u64 {
+                        self.holdings(&EgldOrEsdtTokenIdentifier::egld())
+                            .update(|h| *h -= &payout);
+                        self.send().direct_egld(&member, &payout);
+                        op.amount_distributed += &payout;
+                        self.epoch_spent(current_epoch).update(|s| *s += &payout);
+                    }
+                }
+            }
+
+            idx += 1;
+        }
+        op.next_member_index = idx;
+
+        if op.next_member_index >= total_members {
+            self.ongoing_operation().clear();
+            self.operation_members_snapshot().clear();
+            self.operation_completed_event(&op.amount_distributed);
+            OperationCompletionStatus::Completed
+        } else {
+            self.ongoing_operation().set(&op);
+            self.operation_progress_event(op.next_member_index, total_members);
+            OperationCompletionStatus::InterruptedBeforeOutOfGas
+        }
+    }
+
+    // ========================================================
+    // ENDPOINT: claimStream
+    // Lets a `StreamPayout` receiver pull the accrued-but-unclaimed
+    // balance, bounded by epochs elapsed since `start_epoch`. Routed
+    // through the same epoch_spent guard as a direct EGLD payout.
+    // ========================================================
+
+    #[endpoint(claimStream)]
+    fn claim_stream(&self, proposal_id: u64) {
+        require!(
+            !self.proposals(proposal_id).is_empty(),
+            "Proposal does not exist"
+        );
+        let mut proposal = self.proposals(proposal_id).get();
         require!(
-            current_aum >= proposal.amount,
-            "Insufficient fund balance"
+            proposal.status == ProposalStatus::Executed,
+            "Proposal has not been executed"
         );
 
-        // Execute
-        proposal.status = ProposalStatus::Executed;
+        let caller = self.blockchain().get_caller();
+        let receiver = match &proposal.action {
+            Action::StreamPayout { receiver, .. } => receiver.clone(),
+            _ => sc_panic!("Proposal is not a stream payout"),
+        };
+        require!(caller == receiver, "Only the stream receiver can claim");
+
+        require!(proposal.stream.is_some(), "Stream has no active schedule");
+        let mut stream = proposal.stream.clone().unwrap();
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(current_epoch >= stream.start_epoch, "Stream has not started yet");
+
+        let elapsed_epoch = core::cmp::min(current_epoch, stream.end_epoch);
+        let epochs_accrued = elapsed_epoch - stream.start_epoch + 1;
+        let total_accrued = &stream.amount_per_epoch * epochs_accrued;
+        require!(
+            total_accrued > stream.claimed_so_far,
+            "Nothing accrued to claim yet"
+        );
+        let claimable = &total_accrued - &stream.claimed_so_far;
+
+        let current_aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
+        let epoch_limit = (&current_aum * MAX_EPOCH_SPEND_BPS) / BPS_DENOMINATOR;
+        let already_spent = self.epoch_spent(current_epoch).get();
+        require!(
+            &already_spent + &claimable <= epoch_limit,
+            "Epoch spending limit reached (25% of AUM)"
+        );
+        require!(current_aum >= claimable, "Insufficient fund balance");
+
+        stream.claimed_so_far = total_accrued;
+        proposal.stream = Some(stream);
         self.proposals(proposal_id).set(&proposal);
-        self.epoch_spent(current_epoch)
-            .update(|spent| *spent += &proposal.amount);
+        self.epoch_spent(current_epoch).update(|s| *s += &claimable);
+        self.holdings(&EgldOrEsdtTokenIdentifier::egld())
+            .update(|h| *h -= &claimable);
+
+        self.send().direct_egld(&receiver, &claimable);
 
-        self.send().direct_egld(&proposal.receiver, &proposal.amount);
-        self.proposal_executed_event(proposal_id, &proposal.receiver, &proposal.amount);
+        self.stream_claimed_event(proposal_id, &receiver, &claimable);
     }
 
     // ========================================================
@@ -451,7 +1299,7 @@ pub trait AutonomousFund {
 
         let now = self.blockchain().get_block_timestamp();
         require!(
-            now > proposal.created_at + VOTING_PERIOD,
+            now > proposal.created_at + self.voting_period().get(),
             "Voting period has not ended"
         );
 
@@ -476,6 +1324,48 @@ pub trait AutonomousFund {
         }
     }
 
+    // ========================================================
+    // INTERNAL: quorum check
+    // Quorum is met when total participating weight (yes + no) reaches
+    // `quorum_numerator / quorum_denominator` of the voting-eligible
+    // shares snapshotted at submission time — a real participation
+    // quorum, not just a yes-side bar, and immune to the denominator
+    // shifting under an in-flight vote.
+    // ========================================================
+
+    fn quorum_met(&self, proposal: &Proposal<Self::Api>) -> bool {
+        let effective_shares = &proposal.eligible_shares_snapshot;
+        let participating = &proposal.yes_votes + &proposal.no_votes;
+        &participating * self.quorum_denominator().get()
+            >= effective_shares * self.quorum_numerator().get()
+    }
+
+    // ========================================================
+    // INTERNAL: bonding-curve buy-side inversion
+    // Solves `slope*n^2 + b*n - 2*payment = 0` for `n`, where
+    // `b = 2*initial_price + 2*slope*supply - slope`, i.e. inverts the
+    // cost integral so a payment amount yields a share count directly
+    // rather than requiring the caller to already know `n`.
+    // ========================================================
+
+    fn shares_for_payment(
+        &self,
+        curve: &LinearCurve<Self::Api>,
+        supply: &BigUint,
+        payment: &BigUint,
+    ) -> BigUint {
+        if curve.slope == 0u64 {
+            return payment / &curve.initial_price;
+        }
+
+        let two = BigUint::from(2u64);
+        let b = &two * &curve.initial_price + &two * &curve.slope * supply - &curve.slope;
+        let discriminant = &b * &b + &BigUint::from(8u64) * &curve.slope * payment;
+        let sqrt_discriminant = discriminant.sqrt();
+
+        (sqrt_discriminant - b) / (&two * &curve.slope)
+    }
+
     // ========================================================
     // INTERNAL: rage-quit processing
     // When an agent withdraws, remove their vote weight from
@@ -506,13 +1396,13 @@ pub trait AutonomousFund {
             match proposal.status {
                 ProposalStatus::Open => {
                     // Only if voting window is still active
-                    if now > proposal.created_at + VOTING_PERIOD {
+                    if now > proposal.created_at + self.voting_period().get() {
                         continue;
                     }
                 }
                 ProposalStatus::Passed => {
                     // Only if still within time-lock window
-                    if now > proposal.passed_at + TIMELOCK_PERIOD {
+                    if now > proposal.passed_at + self.timelock_period().get() {
                         continue;
                     }
                 }
@@ -545,12 +1435,12 @@ pub trait AutonomousFund {
 
             match proposal.status {
                 ProposalStatus::Open => {
-                    if now > proposal.created_at + VOTING_PERIOD {
+                    if now > proposal.created_at + self.voting_period().get() {
                         continue;
                     }
                 }
                 ProposalStatus::Passed => {
-                    if now > proposal.passed_at + TIMELOCK_PERIOD {
+                    if now > proposal.passed_at + self.timelock_period().get() {
                         continue;
                     }
                 }
@@ -601,6 +1491,58 @@ pub trait AutonomousFund {
         }
     }
 
+    // ========================================================
+    // INTERNAL: donate reward accrual
+    // `reward_per_share(token)` is a running total scaled by
+    // `REWARD_SCALE`; an agent's lifetime entitlement at any instant is
+    // `shares * reward_per_share / REWARD_SCALE`. `reward_debt` freezes
+    // that figure at the agent's last touch, so the difference is
+    // exactly what accrued since then — the standard MasterChef
+    // accumulator shape.
+    // ========================================================
+
+    fn accrued_reward(&self, shares: &BigUint, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        (shares * &self.reward_per_share(token).get()) / REWARD_SCALE
+    }
+
+    /// Moves whatever accrued to `agent` for `token` since their last
+    /// touch into `claimable_rewards`, using `shares` as it stood *before*
+    /// this call's balance change (if any). Does not move the debt
+    /// snapshot forward — the caller does that once the new share
+    /// balance is known, via `reward_debt(...).set(...)`.
+    fn harvest_reward(&self, agent: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier, shares: &BigUint) {
+        let accrued = self.accrued_reward(shares, token);
+        let debt = self.reward_debt(agent, token).get();
+        if accrued > debt {
+            let pending = &accrued - &debt;
+            self.claimable_rewards(agent, token)
+                .update(|c| *c += &pending);
+        }
+    }
+
+    /// Harvests every token's pending accrual for `agent` against
+    /// `shares` into `claimable_rewards`, without moving the debt
+    /// snapshot. Call with the share balance as it stood *before* a
+    /// deposit/withdraw/redeem changes it.
+    fn harvest_all_rewards(&self, agent: &ManagedAddress, shares: &BigUint) {
+        for token in self.held_tokens().iter() {
+            self.harvest_reward(agent, &token, shares);
+        }
+    }
+
+    /// Re-snapshots every token's `reward_debt` for `agent` to match
+    /// `shares`, so a later `donate` only credits them for what accrues
+    /// after this point. Call with the share balance as it stands
+    /// *after* a deposit/withdraw/redeem changes it — bracketing the
+    /// balance change with `harvest_all_rewards` beforehand and this
+    /// afterward is what keeps accrual correctly attributed.
+    fn resnapshot_all_reward_debt(&self, agent: &ManagedAddress, shares: &BigUint) {
+        for token in self.held_tokens().iter() {
+            self.reward_debt(agent, &token)
+                .set(self.accrued_reward(shares, &token));
+        }
+    }
+
     // ========================================================
     // VIEWS — read-only queries
     // ========================================================
@@ -648,7 +1590,7 @@ pub trait AutonomousFund {
             match proposal.status {
                 ProposalStatus::Open => {
                     // Only include if voting window hasn't expired
-                    if now <= proposal.created_at + VOTING_PERIOD {
+                    if now <= proposal.created_at + self.voting_period().get() {
                         result.push(proposal);
                     }
                 }
@@ -663,9 +1605,7 @@ pub trait AutonomousFund {
 
     #[view(getFundStats)]
     fn get_fund_stats(&self) -> MultiValue5<BigUint, BigUint, u64, u64, u64> {
-        let aum = self
-            .blockchain()
-            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
         let shares = self.total_shares().get();
         let member_count = self.members().len() as u64;
         let proposal_count = self.proposal_count().get();
@@ -679,12 +1619,30 @@ pub trait AutonomousFund {
         if total_shares == 0u64 {
             return BigUint::from(10u64.pow(18)); // 1 CLAW = 1 share initially
         }
-        let current_aum = self
-            .blockchain()
-            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let current_aum = self.holdings(&EgldOrEsdtTokenIdentifier::egld()).get();
         (current_aum * BigUint::from(10u64.pow(18))) / total_shares
     }
 
+    #[view(getBuyPrice)]
+    fn get_buy_price(&self) -> BigUint {
+        require!(!self.share_curve().is_empty(), "No bonding curve configured");
+        let curve = self.share_curve().get();
+        let total_shares = self.total_shares().get();
+        &curve.initial_price + &(&curve.slope * &total_shares)
+    }
+
+    #[view(getSellPrice)]
+    fn get_sell_price(&self) -> BigUint {
+        require!(!self.share_curve().is_empty(), "No bonding curve configured");
+        let curve = self.share_curve().get();
+        let total_shares = self.total_shares().get();
+        if total_shares == 0u64 {
+            return curve.initial_price;
+        }
+        let prior_supply = &total_shares - &BigUint::from(1u64);
+        &curve.initial_price + &(&curve.slope * &prior_supply)
+    }
+
     #[view(getMembers)]
     fn get_members(&self, from: u64, count: u64) -> MultiValueEncoded<ManagedAddress> {
         let mut result = MultiValueEncoded::new();
@@ -708,11 +1666,50 @@ pub trait AutonomousFund {
         self.shares(agent).get()
     }
 
+    #[view(getEffectiveVoteWeight)]
+    fn get_effective_vote_weight(&self, agent: ManagedAddress) -> BigUint {
+        let raw_stake = self.shares(&agent).get();
+        self.reputation_weight(&agent, &raw_stake)
+    }
+
+    #[view(getHoldings)]
+    fn get_holdings(&self, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.holdings(&token).get()
+    }
+
+    #[view(getClaimableRewards)]
+    fn get_claimable_rewards(&self, agent: ManagedAddress, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        let shares = self.shares(&agent).get();
+        let accrued = self.accrued_reward(&shares, &token);
+        let debt = self.reward_debt(&agent, &token).get();
+        let pending = if accrued > debt {
+            &accrued - &debt
+        } else {
+            BigUint::zero()
+        };
+        self.claimable_rewards(&agent, &token).get() + pending
+    }
+
+    #[view(getGuardianSet)]
+    fn get_guardian_set(&self, index: u64) -> GuardianSet<Self::Api> {
+        self.guardian_sets(index).get()
+    }
+
+    #[view(getGuardianSignatureBitmap)]
+    fn get_guardian_signature_bitmap(&self, proposal_id: u64) -> u64 {
+        self.guardian_signature_bitmap(proposal_id).get()
+    }
+
     #[view(getEpochSpent)]
     fn get_epoch_spent(&self, epoch: u64) -> BigUint {
         self.epoch_spent(epoch).get()
     }
 
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.paused().get()
+    }
+
     #[view(getVoteRecords)]
     fn get_vote_records(&self, proposal_id: u64) -> MultiValueEncoded<VoteRecord<Self::Api>> {
         let mut result = MultiValueEncoded::new();
@@ -728,11 +1725,117 @@ pub trait AutonomousFund {
         self.has_voted(proposal_id, agent).get()
     }
 
+    // ========================================================
+    // VIEWS — bulk external-view queries
+    // Mirrors the multisig "pending action full info" pattern: a
+    // frontend fetches everything about a proposal (and the vote
+    // records behind it) in one round trip instead of N.
+    //
+    // Scope note: these were originally requested as a separate,
+    // labeled external-view contract (a `ProposalViewProxy` calling
+    // into it, alongside `UptimeProxy`/`BondRegistryProxy`). They're
+    // implemented as plain views on `AutonomousFund` instead, since a
+    // view-only contract calling back into this one for its own
+    // storage has no purpose the views don't already serve directly.
+    // The generated `ProposalViewProxy` was removed as dead code; no
+    // separate view contract exists.
+    // ========================================================
+
+    #[view(getProposalFullInfo)]
+    fn get_proposal_full_info(&self, id: u64) -> ProposalFullInfo<Self::Api> {
+        require!(!self.proposals(id).is_empty(), "Proposal does not exist");
+        self.build_proposal_full_info(id)
+    }
+
+    #[view(getPendingProposals)]
+    fn get_pending_proposals(
+        &self,
+        from: u64,
+        size: u64,
+    ) -> MultiValueEncoded<ProposalFullInfo<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        if size == 0 {
+            return result;
+        }
+        let total = self.proposal_count().get();
+        if total == 0 {
+            return result;
+        }
+        let start = if from == 0 { 1u64 } else { from };
+        if start > total {
+            return result;
+        }
+        let end = core::cmp::min(start.saturating_add(size - 1), total);
+
+        for i in start..=end {
+            if self.proposals(i).is_empty() {
+                continue;
+            }
+            let status = self.proposals(i).get().status;
+            if status == ProposalStatus::Open
+                || status == ProposalStatus::Passed
+                || status == ProposalStatus::Executable
+            {
+                result.push(self.build_proposal_full_info(i));
+            }
+        }
+        result
+    }
+
+    /// Assembles the bulk view payload for a single proposal: the
+    /// proposal itself, every cast vote, and derived progress fields.
+    fn build_proposal_full_info(&self, id: u64) -> ProposalFullInfo<Self::Api> {
+        let proposal = self.proposals(id).get();
+
+        let mut vote_records = ManagedVec::new();
+        let count = self.vote_records(id).len();
+        for i in 1..=count {
+            vote_records.push(self.vote_records(id).get(i));
+        }
+
+        let effective_shares = &proposal.eligible_shares_snapshot;
+        let quorum_progress_bps = if *effective_shares == 0u64 {
+            0u64
+        } else {
+            (((&proposal.yes_votes + &proposal.no_votes) * BPS_DENOMINATOR) / effective_shares)
+                .to_u64()
+                .unwrap_or(0u64)
+        };
+
+        let now = self.blockchain().get_block_timestamp();
+        let timelock_remaining = if proposal.status == ProposalStatus::Passed {
+            let deadline = proposal.passed_at + self.timelock_period().get();
+            if now < deadline {
+                deadline - now
+            } else {
+                0u64
+            }
+        } else {
+            0u64
+        };
+
+        ProposalFullInfo {
+            proposal,
+            vote_records,
+            quorum_progress_bps,
+            timelock_remaining,
+        }
+    }
+
     #[view(getContractConfig)]
-    fn get_contract_config(&self) -> MultiValue4<BigUint, u64, u64, u64> {
+    fn get_contract_config(&self) -> MultiValue7<BigUint, u64, u64, u64, u64, u64, BigUint> {
         let min_dep = self.min_deposit().get();
         let min_up = self.min_uptime_score().get();
-        (min_dep, min_up, VOTING_PERIOD, TIMELOCK_PERIOD).into()
+        (
+            min_dep,
+            min_up,
+            self.voting_period().get(),
+            self.timelock_period().get(),
+            self.quorum_numerator().get(),
+            self.quorum_denominator().get(),
+            self.proposal_threshold_shares().get(),
+        )
+            .into()
     }
 
     // ========================================================
@@ -764,6 +1867,25 @@ pub trait AutonomousFund {
         timestamp: u64,
     );
 
+    #[event("redeem")]
+    fn redeem_event(&self, #[indexed] agent: &ManagedAddress, shares: &BigUint);
+
+    #[event("donate")]
+    fn donate_event(
+        &self,
+        #[indexed] donor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("rewardsClaimed")]
+    fn rewards_claimed_event(
+        &self,
+        #[indexed] agent: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
     #[event("vote")]
     fn vote_event(
         &self,
@@ -773,6 +1895,24 @@ pub trait AutonomousFund {
         weight: &BigUint,
     );
 
+    #[event("guardianVotesSubmitted")]
+    fn guardian_votes_submitted_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        valid_signature_count: u32,
+    );
+
+    #[event("voteChanged")]
+    fn vote_changed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] old_support: bool,
+        #[indexed] new_support: bool,
+        old_weight: &BigUint,
+        new_weight: &BigUint,
+    );
+
     #[event("proposalPassed")]
     fn proposal_passed_event(
         &self,
@@ -784,7 +1924,10 @@ pub trait AutonomousFund {
     fn proposal_failed_event(&self, #[indexed] proposal_id: u64);
 
     #[event("proposalExecuted")]
-    fn proposal_executed_event(
+    fn proposal_executed_event(&self, #[indexed] proposal_id: u64);
+
+    #[event("streamClaimed")]
+    fn stream_claimed_event(
         &self,
         #[indexed] proposal_id: u64,
         #[indexed] receiver: &ManagedAddress,
@@ -805,6 +1948,25 @@ pub trait AutonomousFund {
         #[indexed] agent: &ManagedAddress,
     );
 
+    #[event("operationInterrupted")]
+    fn operation_interrupted_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        next_index: u64,
+    );
+
+    #[event("operationProgress")]
+    fn operation_progress_event(&self, #[indexed] next_member_index: u64, total_members: u64);
+
+    #[event("operationCompleted")]
+    fn operation_completed_event(&self, amount_distributed: &BigUint);
+
+    #[event("paused")]
+    fn paused_event(&self);
+
+    #[event("unpaused")]
+    fn unpaused_event(&self);
+
     // ========================================================
     // STORAGE
     // ========================================================
@@ -823,6 +1985,47 @@ pub trait AutonomousFund {
     #[storage_mapper("minUptimeScore")]
     fn min_uptime_score(&self) -> SingleValueMapper<u64>;
 
+    // ── Emergency pause ──
+
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    // ── Self-amending governance parameters ──
+    // Set to defaults in `init`; after that only ever written by a passed
+    // `ChangeConfig` proposal. No owner admin endpoint exists for these.
+
+    #[storage_mapper("quorumNumerator")]
+    fn quorum_numerator(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("quorumDenominator")]
+    fn quorum_denominator(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votingPeriod")]
+    fn voting_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelockPeriod")]
+    fn timelock_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalThresholdShares")]
+    fn proposal_threshold_shares(&self) -> SingleValueMapper<BigUint>;
+
+    /// Optional bonding-curve pricing; empty means flat NAV pricing.
+    #[storage_mapper("shareCurve")]
+    fn share_curve(&self) -> SingleValueMapper<LinearCurve<Self::Api>>;
+
+    /// The single in-flight fund-wide batch operation, if any.
+    #[storage_mapper("ongoingOperation")]
+    fn ongoing_operation(&self) -> SingleValueMapper<OngoingOperation<Self::Api>>;
+
+    /// Snapshot of `members()` taken when a fund-wide operation starts.
+    /// `continueOperation` walks this fixed list by index instead of
+    /// `members()` directly, since `members()` is an `UnorderedSetMapper`
+    /// and `withdraw`/`redeem` can `swap_remove` from it mid-drain — walking
+    /// the live set by position would silently skip whoever gets swapped
+    /// into an already-processed slot. Cleared once the operation completes.
+    #[storage_mapper("operationMembersSnapshot")]
+    fn operation_members_snapshot(&self) -> VecMapper<ManagedAddress>;
+
     // ── Fund state ──
 
     #[storage_mapper("totalShares")]
@@ -831,6 +2034,66 @@ pub trait AutonomousFund {
     #[storage_mapper("shares")]
     fn shares(&self, agent: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// Per-token principal backing share NAV — the single source of
+    /// truth for every payout path (`withdraw`, `redeem`, proposal
+    /// spends, stream claims). Kept in sync explicitly at every site
+    /// that moves funds in or out, rather than read live off
+    /// `get_sc_balance`, so a pending `reward_pool` donation (or any
+    /// other balance the fund isn't free to spend) never leaks into it.
+    #[storage_mapper("holdings")]
+    fn holdings(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Every token ever deposited, so `redeem` knows what to iterate
+    /// without scanning unrelated storage.
+    #[storage_mapper("heldTokens")]
+    fn held_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // ── Donate reward accumulator ──
+
+    /// Donated `token` not yet claimed via `claimRewards`. Kept separate
+    /// from `holdings` so undistributed donations never leak into
+    /// `withdraw`/`redeem` NAV pricing.
+    #[storage_mapper("rewardPool")]
+    fn reward_pool(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Running total of donated `token` per share, scaled by
+    /// `REWARD_SCALE`. Only ever grows, via `donate`.
+    #[storage_mapper("rewardPerShare")]
+    fn reward_per_share(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// `agent`'s `reward_per_share(token)`-scaled entitlement as of their
+    /// last deposit/withdraw/redeem/claim — everything above this is
+    /// unclaimed accrual.
+    #[storage_mapper("rewardDebt")]
+    fn reward_debt(&self, agent: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Accrued `token` owed to `agent` but not yet paid out by `claimRewards`.
+    #[storage_mapper("claimableRewards")]
+    fn claimable_rewards(&self, agent: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // ── Guardian-set ratification ──
+    // Like the self-amending governance parameters, the guardian set
+    // itself is only ever rotated via a passed `SetGuardianSet` proposal.
+
+    #[storage_mapper("guardianSets")]
+    fn guardian_sets(&self, index: u64) -> SingleValueMapper<GuardianSet<Self::Api>>;
+
+    #[storage_mapper("currentGuardianSetIndex")]
+    fn current_guardian_set_index(&self) -> SingleValueMapper<u64>;
+
+    /// Bumped every time the guardian set rotates, invalidating any
+    /// digest signed against a now-retired committee.
+    #[storage_mapper("guardianNonce")]
+    fn guardian_nonce(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("guardianVoteDigest")]
+    fn guardian_vote_digest(&self, proposal_id: u64) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    /// Bitmap of guardian indices (within the current set) that have
+    /// already submitted a valid signature for this proposal.
+    #[storage_mapper("guardianSignatureBitmap")]
+    fn guardian_signature_bitmap(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
     #[storage_mapper("members")]
     fn members(&self) -> UnorderedSetMapper<ManagedAddress>;
 
@@ -848,6 +2111,12 @@ pub trait AutonomousFund {
     #[storage_mapper("hasVoted")]
     fn has_voted(&self, proposal_id: u64, voter: &ManagedAddress) -> SingleValueMapper<bool>;
 
+    /// Resumable-execution cursor. Empty when no execution is in progress
+    /// for this proposal; set only while a payout loop has been
+    /// interrupted for being low on gas.
+    #[storage_mapper("executionCursor")]
+    fn execution_cursor(&self, proposal_id: u64) -> SingleValueMapper<ExecutionCursor<Self::Api>>;
+
     // ── Spending limits ──
 
     #[storage_mapper("epochSpent")]
@@ -23,6 +23,72 @@ pub enum ProposalStatus {
     Cancelled,
 }
 
+// ============================================================
+// Action — what an Executable proposal actually does on-chain
+// ============================================================
+
+/// The on-chain effect of a proposal once it reaches `Executable`.
+/// Generalizes beyond a plain EGLD transfer so governance can direct
+/// the fund to hold ESDTs or call out to arbitrary contracts (staking,
+/// re-delegating, registering bonds against `BondRegistryProxy`, etc).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum Action<M: ManagedTypeApi> {
+    SendEgld {
+        to: ManagedAddress<M>,
+        amount: BigUint<M>,
+    },
+    SendEsdt {
+        to: ManagedAddress<M>,
+        token: TokenIdentifier<M>,
+        nonce: u64,
+        amount: BigUint<M>,
+    },
+    AsyncCall {
+        to: ManagedAddress<M>,
+        endpoint: ManagedBuffer<M>,
+        gas: u64,
+        args: ManagedVec<M, ManagedBuffer<M>>,
+    },
+    /// Self-amends the DAO's own governance parameters. This is the only
+    /// way those parameters can change — there is no owner admin path.
+    ChangeConfig {
+        quorum_numerator: u64,
+        quorum_denominator: u64,
+        voting_period: u64,
+        timelock_period: u64,
+        proposal_threshold_shares: BigUint<M>,
+    },
+    /// Switches share pricing to (or re-parameterizes) a linear bonding
+    /// curve. Like `ChangeConfig`, only settable via a passed proposal.
+    SetShareCurve {
+        initial_price: BigUint<M>,
+        slope: BigUint<M>,
+    },
+    /// Distributes `amount` pro-rata across every current member. Too
+    /// large to pay out in one transaction for a big fund, so execution
+    /// only starts the ongoing operation; `continueOperation` drains it.
+    DistributeSurplus { amount: BigUint<M> },
+    /// Starts a recurring per-epoch payout to `receiver`, claimable
+    /// incrementally via `claimStream` rather than paid out all at once.
+    StreamPayout {
+        receiver: ManagedAddress<M>,
+        amount_per_epoch: BigUint<M>,
+        start_epoch: u64,
+        end_epoch: u64,
+    },
+    /// Caps a previously-started stream's `end_epoch` at the current
+    /// epoch, freezing further accrual while leaving what's already
+    /// accrued claimable.
+    CancelStream { target_proposal_id: u64 },
+    /// Rotates the guardian committee used by `submitSignedVotes`. Like
+    /// `ChangeConfig`, only settable via a passed proposal.
+    SetGuardianSet {
+        guardians: ManagedVec<M, ManagedAddress<M>>,
+        threshold: u32,
+    },
+}
+
 // ============================================================
 // Proposal — the core governance record
 // ============================================================
@@ -33,8 +99,7 @@ pub struct Proposal<M: ManagedTypeApi> {
     pub id: u64,
     pub proposer: ManagedAddress<M>,
     pub description: ManagedBuffer<M>,
-    pub receiver: ManagedAddress<M>,
-    pub amount: BigUint<M>,
+    pub action: Action<M>,
     pub status: ProposalStatus,
     pub yes_votes: BigUint<M>,
     pub no_votes: BigUint<M>,
@@ -43,6 +108,132 @@ pub struct Proposal<M: ManagedTypeApi> {
     pub passed_at: u64,
     /// Bulletin Board post ID linking to the discussion thread
     pub bulletin_post_id: u64,
+    /// Set once an executed `StreamPayout` action starts accruing;
+    /// `None` for every other action kind.
+    pub stream: Option<StreamInfo<M>>,
+    /// Voting-eligible share supply at submission time, frozen so a
+    /// deposit or withdrawal during the voting window can't move the
+    /// quorum denominator out from under an in-flight vote.
+    pub eligible_shares_snapshot: BigUint<M>,
+}
+
+// ============================================================
+// Stream Info — recurring per-epoch payout accrual state
+// ============================================================
+
+/// Tracks accrual for a `StreamPayout` proposal. `claimed_so_far` lets
+/// `claimStream` compute the unclaimed remainder without a separate
+/// per-claim ledger.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct StreamInfo<M: ManagedTypeApi> {
+    pub amount_per_epoch: BigUint<M>,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub claimed_so_far: BigUint<M>,
+}
+
+// ============================================================
+// Guardian Set — off-chain signature-aggregated ratification
+// ============================================================
+
+/// A versioned guardian/trustee committee: the member addresses and the
+/// M-of-N threshold of valid signatures `submitSignedVotes` needs to
+/// ratify a proposal. Bounded to 64 guardians so a signer bitmap fits
+/// in a `u64`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct GuardianSet<M: ManagedTypeApi> {
+    pub guardians: ManagedVec<M, ManagedAddress<M>>,
+    pub threshold: u32,
+}
+
+// ============================================================
+// Bonding curve — optional alternative to flat NAV share pricing
+// ============================================================
+
+/// A pricing function mapping current share supply to the cost of
+/// minting (or refund from burning) the next batch of shares.
+pub trait CurveFunction<M: ManagedTypeApi> {
+    /// Cost to mint `amount` shares starting from `supply` shares outstanding.
+    fn buy_cost(&self, supply: &BigUint<M>, amount: &BigUint<M>) -> BigUint<M>;
+    /// Refund for burning `amount` shares out of `supply` shares outstanding.
+    fn sell_refund(&self, supply: &BigUint<M>, amount: &BigUint<M>) -> BigUint<M>;
+}
+
+/// `price(s) = initial_price + slope * s`. Cost to mint `n` shares starting
+/// at supply `s` is the integral `initial_price*n + slope*(n*s + n*(n-1)/2)`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct LinearCurve<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub slope: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for LinearCurve<M> {
+    fn buy_cost(&self, supply: &BigUint<M>, amount: &BigUint<M>) -> BigUint<M> {
+        let one = BigUint::from(1u64);
+        let triangular = if *amount == 0u64 {
+            BigUint::zero()
+        } else {
+            (amount * &(amount - &one)) / 2u64
+        };
+        &self.initial_price * amount + &self.slope * &(amount * supply + triangular)
+    }
+
+    fn sell_refund(&self, supply: &BigUint<M>, amount: &BigUint<M>) -> BigUint<M> {
+        let start = supply - amount;
+        self.buy_cost(&start, amount)
+    }
+}
+
+// ============================================================
+// Ongoing Operation — resumable, gas-bounded execution
+// ============================================================
+
+/// Result of a single call into a gas-bounded ongoing operation.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum OperationCompletionStatus {
+    /// The operation ran to completion in this call.
+    Completed,
+    /// Gas ran low; progress was persisted so the caller can re-invoke to resume.
+    InterruptedBeforeOutOfGas,
+}
+
+/// Cursor for a proposal's payout loop: which receiver is next, and how much
+/// has been paid out so far. Persisted across calls so execution can be
+/// resumed without double-paying or losing progress.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ExecutionCursor<M: ManagedTypeApi> {
+    pub next_index: u64,
+    pub amount_sent: BigUint<M>,
+}
+
+/// Kinds of fund-wide batch operation that can be left ongoing across
+/// multiple `continueOperation` calls. Deliberately a single-variant
+/// enum today so later batch kinds (mass NAV recompute, refund-all-voters)
+/// can be added without reshaping `OngoingOperation`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum OperationKind {
+    DistributeSurplus,
+}
+
+/// Progress cursor for a fund-wide operation that walks every entry in
+/// `members()` — too large to do in one transaction once the fund has
+/// many members. Only one such operation can be in flight at a time.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct OngoingOperation<M: ManagedTypeApi> {
+    pub kind: OperationKind,
+    pub next_member_index: u64,
+    pub amount_distributed: BigUint<M>,
+    pub total_amount: BigUint<M>,
+    /// Total shares at the moment the operation started, so a member
+    /// joining or leaving mid-drain doesn't skew already-computed shares.
+    pub total_shares_snapshot: BigUint<M>,
 }
 
 // ============================================================
@@ -61,5 +252,31 @@ pub enum VoteDirection {
 pub struct VoteRecord<M: ManagedTypeApi> {
     pub voter: ManagedAddress<M>,
     pub direction: VoteDirection,
+    /// Reputation-adjusted weight actually applied to the tally
+    /// (`raw_stake * uptime_factor`).
     pub weight: BigUint<M>,
+    /// Share balance the weight was derived from, kept alongside the
+    /// computed weight so rage-quit recomputation stays deterministic.
+    pub raw_stake: BigUint<M>,
+}
+
+// ============================================================
+// Proposal Full Info — bulk view payload for getProposalFullInfo /
+// getPendingProposals
+// ============================================================
+
+/// Everything a frontend needs about one proposal in a single query:
+/// the proposal itself, every vote cast on it, and derived progress
+/// fields that would otherwise require re-deriving constants client-side.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct ProposalFullInfo<M: ManagedTypeApi> {
+    pub proposal: Proposal<M>,
+    pub vote_records: ManagedVec<M, VoteRecord<M>>,
+    /// Participating (yes + no) weight as a share of the voting-eligible
+    /// shares snapshotted at submission, in basis points (10_000 = 100%).
+    /// Quorum is met once this reaches `quorum_numerator / quorum_denominator`.
+    pub quorum_progress_bps: u64,
+    /// Seconds remaining in the time-lock, 0 if elapsed or not yet Passed.
+    pub timelock_remaining: u64,
 }
@@ -34,6 +34,10 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Returns `(total_periods, active_periods, successful_cycles, strikes)`
+    /// for `agent` over its whole registered lifetime. `active_periods /
+    /// total_periods` is the uptime ratio; `strikes` counts penalized
+    /// violations. Every call site destructures this in that order.
     pub fn get_lifetime_info<Arg0: ProxyArg<ManagedAddress<Env::Api>>>(
         self,
         agent: Arg0,
@@ -44,4 +48,26 @@ where
             .argument(&agent)
             .original_result()
     }
+
+    /// Returns up to `num_epochs` trailing `(epoch, credits, prev_credits)`
+    /// tuples for `agent`, most recent first.
+    pub fn get_epoch_credits<Arg0: ProxyArg<ManagedAddress<Env::Api>>>(
+        self,
+        agent: Arg0,
+        num_epochs: u64,
+    ) -> TxTypedCall<
+        Env,
+        From,
+        To,
+        NotPayable,
+        Gas,
+        MultiValueEncoded<Env::Api, MultiValue3<u64, u64, u64>>,
+    > {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEpochCredits")
+            .argument(&agent)
+            .argument(&num_epochs)
+            .original_result()
+    }
 }
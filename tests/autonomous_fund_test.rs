@@ -1,10 +1,10 @@
-use multiversx_sc::types::Address;
+use multiversx_sc::types::{Address, EgldOrEsdtTokenIdentifier};
 use multiversx_sc_scenario::{
     api::DebugApi,
     managed_address, managed_biguint, rust_biguint, whitebox_legacy::*,
 };
 
-use autonomous_fund::*;
+use autonomous_fund::{types::Action, *};
 
 const WASM_PATH: &'static str = "output/autonomous-fund.wasm";
 
@@ -38,9 +38,20 @@ where
         WASM_PATH,
     );
 
+    // Bond-registry/uptime addresses are plain user accounts with no
+    // contract code behind them; `deposit`/`vote` still issue their
+    // cross-contract reputation calls against these addresses.
+    let bond_registry_address = blockchain_wrapper.create_user_account(&rust_biguint!(0));
+    let uptime_address = blockchain_wrapper.create_user_account(&rust_biguint!(0));
+
     blockchain_wrapper
         .execute_tx(&owner_address, &contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
-            sc.init();
+            sc.init(
+                managed_address!(&bond_registry_address),
+                managed_address!(&uptime_address),
+                managed_biguint!(0),
+                0u64,
+            );
         })
         .assert_ok();
 
@@ -71,6 +82,79 @@ fn test_deposit() {
         .assert_ok();
 }
 
+#[test]
+fn test_redeem() {
+    let mut setup = setup_fund(autonomous_fund::contract_obj);
+    let user = setup.blockchain_wrapper.create_user_account(&rust_biguint!(10_000));
+
+    setup.blockchain_wrapper
+        .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(10_000), |sc: FundContract| {
+            sc.deposit();
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper
+        .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
+            sc.redeem(managed_biguint!(5_000));
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc: FundContract| {
+            let shares = sc.shares(&managed_address!(&user)).get();
+            assert_eq!(shares, managed_biguint!(5_000));
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_egld_balance(&user, &rust_biguint!(4_545));
+}
+
+#[test]
+fn test_donate_and_claim_rewards() {
+    let mut setup = setup_fund(autonomous_fund::contract_obj);
+    let user_a = setup.blockchain_wrapper.create_user_account(&rust_biguint!(20_000));
+    let user_b = setup.blockchain_wrapper.create_user_account(&rust_biguint!(2_000));
+    let donor = setup.blockchain_wrapper.create_user_account(&rust_biguint!(2_000));
+
+    // Two depositors: A mints 9_000 shares (first deposit, 1:1), B mints
+    // 1_000 shares against the 9_000+1_000 dead-share pool already there.
+    setup.blockchain_wrapper
+        .execute_tx(&user_a, &setup.contract_wrapper, &rust_biguint!(9_000), |sc: FundContract| {
+            sc.deposit();
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper
+        .execute_tx(&user_b, &setup.contract_wrapper, &rust_biguint!(900), |sc: FundContract| {
+            sc.deposit();
+        })
+        .assert_ok();
+
+    // A single donation, split pro-rata by share (9_000 : 1_000 : 1_000 dead).
+    setup.blockchain_wrapper
+        .execute_tx(&donor, &setup.contract_wrapper, &rust_biguint!(1_100), |sc: FundContract| {
+            sc.donate();
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper
+        .execute_tx(&user_a, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
+            sc.claim_rewards(EgldOrEsdtTokenIdentifier::egld());
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper
+        .execute_tx(&user_b, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
+            sc.claim_rewards(EgldOrEsdtTokenIdentifier::egld());
+        })
+        .assert_ok();
+
+    // A's 9_000 shares accrue 900, B's 1_000 shares accrue 100 — the
+    // remaining 100 (dead shares' portion) is left unclaimable in the fund.
+    setup.blockchain_wrapper.check_egld_balance(&user_a, &rust_biguint!(11_900));
+    setup.blockchain_wrapper.check_egld_balance(&user_b, &rust_biguint!(1_200));
+}
+
 #[test]
 fn test_proposal_flow() {
     let mut setup = setup_fund(autonomous_fund::contract_obj);
@@ -84,13 +168,17 @@ fn test_proposal_flow() {
         })
         .assert_ok();
 
-    // 2. Submit Proposal
+    // 2. Submit Proposal — amount must stay within the 15%-of-AUM
+    // per-proposal cap (AUM is the 100 just deposited).
     setup.blockchain_wrapper
         .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
             sc.submit_proposal(
                 multiversx_sc::types::ManagedBuffer::from(b"Invest"),
-                managed_address!(&receiver),
-                managed_biguint!(50),
+                Action::SendEgld {
+                    to: managed_address!(&receiver),
+                    amount: managed_biguint!(10),
+                },
+                0u64,
             );
         })
         .assert_ok();
@@ -98,11 +186,30 @@ fn test_proposal_flow() {
     // 3. Vote
     setup.blockchain_wrapper
         .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
-            sc.vote(1);
+            sc.vote(1, true);
+        })
+        .assert_ok();
+
+    // Default voting window and time-lock are both 24h (86_400s) — see
+    // VOTING_PERIOD / TIMELOCK_PERIOD in src/lib.rs.
+    const VOTING_PERIOD: u64 = 86_400;
+    const TIMELOCK_PERIOD: u64 = 86_400;
+
+    // 4. Finalize — a single `vote` isn't enough to execute; the voting
+    // window must actually elapse before `finalizeVoting` can flip the
+    // proposal to `Passed`, and the time-lock must elapse after that
+    // before `executeProposal` will accept it.
+    setup.blockchain_wrapper
+        .set_block_timestamp(VOTING_PERIOD + 1);
+    setup.blockchain_wrapper
+        .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
+            sc.finalize_voting(1);
         })
         .assert_ok();
 
-    // 4. Execute
+    // 5. Execute
+    setup.blockchain_wrapper
+        .set_block_timestamp(VOTING_PERIOD + TIMELOCK_PERIOD + 2);
     setup.blockchain_wrapper
         .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0), |sc: FundContract| {
             sc.execute_proposal(1);
@@ -110,5 +217,5 @@ fn test_proposal_flow() {
         .assert_ok();
 
     // Verify receiver got funds
-    setup.blockchain_wrapper.check_egld_balance(&receiver, &rust_biguint!(50));
+    setup.blockchain_wrapper.check_egld_balance(&receiver, &rust_biguint!(10));
 }